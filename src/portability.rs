@@ -11,6 +11,9 @@ use crate::{
     AsHandle, AsSocket, BorrowedHandle, BorrowedSocket, FromHandle, FromSocket, IntoHandle,
     IntoSocket, OwnedHandle, OwnedSocket,
 };
+use std::marker::PhantomData;
+use std::mem::ManuallyDrop;
+use std::ops::{Deref, DerefMut};
 #[cfg(unix)]
 use std::os::unix::io::{AsRawFd, FromRawFd, IntoRawFd, RawFd};
 #[cfg(target_os = "wasi")]
@@ -341,6 +344,32 @@ impl<T: AsRawSocket> AsRawSocketlike for T {
     }
 }
 
+#[cfg(any(unix, target_os = "wasi"))]
+pub(crate) trait AsRawPipelike: AsRawFd {
+    fn as_raw_pipelike(&self) -> RawPipelike;
+}
+
+#[cfg(any(unix, target_os = "wasi"))]
+impl<T: AsRawFd> AsRawPipelike for T {
+    #[inline]
+    fn as_raw_pipelike(&self) -> RawPipelike {
+        self.as_raw_fd()
+    }
+}
+
+#[cfg(windows)]
+pub(crate) trait AsRawPipelike: AsRawHandle {
+    fn as_raw_pipelike(&self) -> RawPipelike;
+}
+
+#[cfg(windows)]
+impl<T: AsRawHandle> AsRawPipelike for T {
+    #[inline]
+    fn as_raw_pipelike(&self) -> RawPipelike {
+        self.as_raw_handle()
+    }
+}
+
 #[cfg(any(unix, target_os = "wasi"))]
 pub(crate) trait IntoRawFilelike: IntoRawFd {
     fn into_raw_filelike(self) -> RawFilelike;
@@ -431,3 +460,593 @@ impl<T: FromRawSocket> FromRawSocketlike for T {
         Self::from_raw_socket(raw)
     }
 }
+
+/// A trait to borrow a filelike reference from an underlying object as a
+/// given `Target` type, without taking ownership of the underlying
+/// descriptor.
+#[cfg(any(unix, target_os = "wasi"))]
+pub trait AsFilelikeView: AsFilelike {
+    /// Borrows the filelike reference as the given `Target` type.
+    fn as_filelike_view<Target: FromFilelike + IntoRawFd>(&self) -> FilelikeView<'_, Target>;
+}
+
+#[cfg(any(unix, target_os = "wasi"))]
+impl<T: AsFilelike> AsFilelikeView for T {
+    #[inline]
+    fn as_filelike_view<Target: FromFilelike + IntoRawFd>(&self) -> FilelikeView<'_, Target> {
+        // Safety: the raw descriptor is borrowed from `self` for the
+        // duration of the returned `FilelikeView`'s lifetime, and the
+        // view's `Drop` impl ensures it's released without being closed.
+        let owned = unsafe { OwnedFilelike::from_raw_fd(self.as_filelike().as_raw_fd()) };
+        FilelikeView {
+            target: ManuallyDrop::new(Target::from_filelike(owned)),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+/// A trait to borrow a filelike reference from an underlying object as a
+/// given `Target` type, without taking ownership of the underlying
+/// descriptor.
+#[cfg(windows)]
+pub trait AsFilelikeView: AsFilelike {
+    /// Borrows the filelike reference as the given `Target` type.
+    fn as_filelike_view<Target: FromFilelike + IntoRawHandle>(&self) -> FilelikeView<'_, Target>;
+}
+
+#[cfg(windows)]
+impl<T: AsFilelike> AsFilelikeView for T {
+    #[inline]
+    fn as_filelike_view<Target: FromFilelike + IntoRawHandle>(&self) -> FilelikeView<'_, Target> {
+        // Safety: the raw handle is borrowed from `self` for the duration
+        // of the returned `FilelikeView`'s lifetime, and the view's `Drop`
+        // impl ensures it's released without being closed.
+        let owned = unsafe { OwnedFilelike::from_raw_handle(self.as_filelike().as_raw_handle()) };
+        FilelikeView {
+            target: ManuallyDrop::new(Target::from_filelike(owned)),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+/// A non-owning view of a filelike object as a given `Target` type.
+///
+/// This is useful for temporarily treating a borrowed filelike reference as
+/// a concrete type such as `std::fs::File`, in order to call methods on it,
+/// without taking ownership of the underlying descriptor. The descriptor is
+/// not closed when the view is dropped.
+#[cfg(any(unix, target_os = "wasi"))]
+pub struct FilelikeView<'owned, Target: FromFilelike + IntoRawFd> {
+    target: ManuallyDrop<Target>,
+    _phantom: PhantomData<BorrowedFilelike<'owned>>,
+}
+
+/// A non-owning view of a filelike object as a given `Target` type.
+///
+/// This is useful for temporarily treating a borrowed filelike reference as
+/// a concrete type such as `std::fs::File`, in order to call methods on it,
+/// without taking ownership of the underlying descriptor. The descriptor is
+/// not closed when the view is dropped.
+#[cfg(windows)]
+pub struct FilelikeView<'owned, Target: FromFilelike + IntoRawHandle> {
+    target: ManuallyDrop<Target>,
+    _phantom: PhantomData<BorrowedFilelike<'owned>>,
+}
+
+#[cfg(any(unix, target_os = "wasi"))]
+impl<Target: FromFilelike + IntoRawFd> Deref for FilelikeView<'_, Target> {
+    type Target = Target;
+
+    #[inline]
+    fn deref(&self) -> &Target {
+        &self.target
+    }
+}
+
+#[cfg(windows)]
+impl<Target: FromFilelike + IntoRawHandle> Deref for FilelikeView<'_, Target> {
+    type Target = Target;
+
+    #[inline]
+    fn deref(&self) -> &Target {
+        &self.target
+    }
+}
+
+#[cfg(any(unix, target_os = "wasi"))]
+impl<Target: FromFilelike + IntoRawFd> DerefMut for FilelikeView<'_, Target> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Target {
+        &mut self.target
+    }
+}
+
+#[cfg(windows)]
+impl<Target: FromFilelike + IntoRawHandle> DerefMut for FilelikeView<'_, Target> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Target {
+        &mut self.target
+    }
+}
+
+#[cfg(any(unix, target_os = "wasi"))]
+impl<Target: FromFilelike + IntoRawFd> Drop for FilelikeView<'_, Target> {
+    #[inline]
+    fn drop(&mut self) {
+        // Consume the raw fd without closing it, since we never took
+        // ownership of the underlying descriptor.
+        let _ = unsafe { ManuallyDrop::take(&mut self.target) }.into_raw_fd();
+    }
+}
+
+#[cfg(windows)]
+impl<Target: FromFilelike + IntoRawHandle> Drop for FilelikeView<'_, Target> {
+    #[inline]
+    fn drop(&mut self) {
+        // Consume the raw handle without closing it, since we never took
+        // ownership of the underlying descriptor.
+        let _ = unsafe { ManuallyDrop::take(&mut self.target) }.into_raw_handle();
+    }
+}
+
+/// A trait to borrow a socketlike reference from an underlying object as a
+/// given `Target` type, without taking ownership of the underlying
+/// descriptor.
+#[cfg(any(unix, target_os = "wasi"))]
+pub trait AsSocketlikeView: AsSocketlike {
+    /// Borrows the socketlike reference as the given `Target` type.
+    fn as_socketlike_view<Target: FromSocketlike + IntoRawFd>(&self) -> SocketlikeView<'_, Target>;
+}
+
+#[cfg(any(unix, target_os = "wasi"))]
+impl<T: AsSocketlike> AsSocketlikeView for T {
+    #[inline]
+    fn as_socketlike_view<Target: FromSocketlike + IntoRawFd>(&self) -> SocketlikeView<'_, Target> {
+        // Safety: the raw descriptor is borrowed from `self` for the
+        // duration of the returned `SocketlikeView`'s lifetime, and the
+        // view's `Drop` impl ensures it's released without being closed.
+        let owned = unsafe { OwnedSocketlike::from_raw_fd(self.as_socketlike().as_raw_fd()) };
+        SocketlikeView {
+            target: ManuallyDrop::new(Target::from_socketlike(owned)),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+/// A trait to borrow a socketlike reference from an underlying object as a
+/// given `Target` type, without taking ownership of the underlying
+/// descriptor.
+#[cfg(windows)]
+pub trait AsSocketlikeView: AsSocketlike {
+    /// Borrows the socketlike reference as the given `Target` type.
+    fn as_socketlike_view<Target: FromSocketlike + IntoRawSocket>(
+        &self,
+    ) -> SocketlikeView<'_, Target>;
+}
+
+#[cfg(windows)]
+impl<T: AsSocketlike> AsSocketlikeView for T {
+    #[inline]
+    fn as_socketlike_view<Target: FromSocketlike + IntoRawSocket>(
+        &self,
+    ) -> SocketlikeView<'_, Target> {
+        // Safety: the raw socket is borrowed from `self` for the duration
+        // of the returned `SocketlikeView`'s lifetime, and the view's
+        // `Drop` impl ensures it's released without being closed.
+        let owned =
+            unsafe { OwnedSocketlike::from_raw_socket(self.as_socketlike().as_raw_socket()) };
+        SocketlikeView {
+            target: ManuallyDrop::new(Target::from_socketlike(owned)),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+/// A non-owning view of a socketlike object as a given `Target` type.
+///
+/// This is useful for temporarily treating a borrowed socketlike reference
+/// as a concrete type such as `std::net::TcpStream`, in order to call
+/// methods on it, without taking ownership of the underlying descriptor.
+/// The descriptor is not closed when the view is dropped.
+#[cfg(any(unix, target_os = "wasi"))]
+pub struct SocketlikeView<'owned, Target: FromSocketlike + IntoRawFd> {
+    target: ManuallyDrop<Target>,
+    _phantom: PhantomData<BorrowedSocketlike<'owned>>,
+}
+
+/// A non-owning view of a socketlike object as a given `Target` type.
+///
+/// This is useful for temporarily treating a borrowed socketlike reference
+/// as a concrete type such as `std::net::TcpStream`, in order to call
+/// methods on it, without taking ownership of the underlying descriptor.
+/// The descriptor is not closed when the view is dropped.
+#[cfg(windows)]
+pub struct SocketlikeView<'owned, Target: FromSocketlike + IntoRawSocket> {
+    target: ManuallyDrop<Target>,
+    _phantom: PhantomData<BorrowedSocketlike<'owned>>,
+}
+
+#[cfg(any(unix, target_os = "wasi"))]
+impl<Target: FromSocketlike + IntoRawFd> Deref for SocketlikeView<'_, Target> {
+    type Target = Target;
+
+    #[inline]
+    fn deref(&self) -> &Target {
+        &self.target
+    }
+}
+
+#[cfg(windows)]
+impl<Target: FromSocketlike + IntoRawSocket> Deref for SocketlikeView<'_, Target> {
+    type Target = Target;
+
+    #[inline]
+    fn deref(&self) -> &Target {
+        &self.target
+    }
+}
+
+#[cfg(any(unix, target_os = "wasi"))]
+impl<Target: FromSocketlike + IntoRawFd> DerefMut for SocketlikeView<'_, Target> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Target {
+        &mut self.target
+    }
+}
+
+#[cfg(windows)]
+impl<Target: FromSocketlike + IntoRawSocket> DerefMut for SocketlikeView<'_, Target> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Target {
+        &mut self.target
+    }
+}
+
+#[cfg(any(unix, target_os = "wasi"))]
+impl<Target: FromSocketlike + IntoRawFd> Drop for SocketlikeView<'_, Target> {
+    #[inline]
+    fn drop(&mut self) {
+        // Consume the raw fd without closing it, since we never took
+        // ownership of the underlying descriptor.
+        let _ = unsafe { ManuallyDrop::take(&mut self.target) }.into_raw_fd();
+    }
+}
+
+#[cfg(windows)]
+impl<Target: FromSocketlike + IntoRawSocket> Drop for SocketlikeView<'_, Target> {
+    #[inline]
+    fn drop(&mut self) {
+        // Consume the raw socket without closing it, since we never took
+        // ownership of the underlying descriptor.
+        let _ = unsafe { ManuallyDrop::take(&mut self.target) }.into_raw_socket();
+    }
+}
+
+/// The kind of OS object that a filelike descriptor actually refers to, as
+/// determined by inspecting it at runtime rather than trusting the static
+/// type that claims to wrap it.
+#[cfg(any(unix, target_os = "wasi"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FilelikeKind {
+    /// A regular file.
+    File,
+    /// A pipe or FIFO.
+    Pipe,
+    /// A socket.
+    Socket,
+    /// Some other kind of object, such as a character or block device.
+    Other,
+}
+
+/// The kind of OS object that a filelike descriptor actually refers to, as
+/// determined by inspecting it at runtime rather than trusting the static
+/// type that claims to wrap it.
+#[cfg(windows)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FilelikeKind {
+    /// A regular disk file.
+    File,
+    /// An anonymous or named pipe.
+    Pipe,
+    /// A character device, such as a console or `NUL`.
+    CharDevice,
+    /// Some other kind of handle.
+    Other,
+}
+
+/// Inspects a raw filelike descriptor to determine what kind of object it
+/// actually refers to.
+#[cfg(any(unix, target_os = "wasi"))]
+pub(crate) fn filelike_kind(raw: RawFilelike) -> std::io::Result<FilelikeKind> {
+    let mut stat = std::mem::MaybeUninit::uninit();
+    if unsafe { libc::fstat(raw, stat.as_mut_ptr()) } != 0 {
+        return Err(std::io::Error::last_os_error());
+    }
+    let stat = unsafe { stat.assume_init() };
+    Ok(match stat.st_mode & libc::S_IFMT {
+        libc::S_IFREG => FilelikeKind::File,
+        libc::S_IFIFO => FilelikeKind::Pipe,
+        libc::S_IFSOCK => FilelikeKind::Socket,
+        _ => FilelikeKind::Other,
+    })
+}
+
+/// Inspects a raw filelike descriptor to determine what kind of object it
+/// actually refers to.
+#[cfg(windows)]
+pub(crate) fn filelike_kind(raw: RawFilelike) -> std::io::Result<FilelikeKind> {
+    use windows_sys::Win32::Storage::FileSystem::{
+        GetFileType, FILE_TYPE_CHAR, FILE_TYPE_DISK, FILE_TYPE_PIPE,
+    };
+
+    // Safety: `raw` is a valid, open handle for the duration of this call.
+    match unsafe { GetFileType(raw as _) } {
+        FILE_TYPE_DISK => Ok(FilelikeKind::File),
+        FILE_TYPE_PIPE => Ok(FilelikeKind::Pipe),
+        FILE_TYPE_CHAR => Ok(FilelikeKind::CharDevice),
+        _ => Ok(FilelikeKind::Other),
+    }
+}
+
+/// A trait to express the ability to attempt to construct an object from a
+/// filelike object, validating that the underlying descriptor is of the
+/// expected kind before committing to the conversion.
+///
+/// Unlike [`FromFilelike`], which trusts the caller, this is useful when
+/// the descriptor came from an untrusted or out-of-band source, such as a
+/// handle duplicated into the process or inherited across a `fork`/
+/// `CreateProcess`, where there's no static guarantee that it's actually
+/// the kind of object `Self` expects.
+#[cfg(any(unix, target_os = "wasi", windows))]
+pub trait TryFromFilelike: FromFilelike {
+    /// The kind of filelike object that `Self` expects to be constructed
+    /// from.
+    const KIND: FilelikeKind;
+
+    /// Constructs a new instance of `Self` from the given filelike object,
+    /// if it is of the expected kind.
+    ///
+    /// On failure, returns the original `owned` object back to the caller,
+    /// so that the descriptor isn't leaked.
+    fn try_from_filelike(owned: OwnedFilelike) -> Result<Self, OwnedFilelike> {
+        match filelike_kind(owned.as_raw_filelike()) {
+            Ok(kind) if kind == Self::KIND => Ok(Self::from_filelike(owned)),
+            _ => Err(owned),
+        }
+    }
+}
+
+#[cfg(any(unix, target_os = "wasi", windows))]
+impl TryFromFilelike for std::fs::File {
+    const KIND: FilelikeKind = FilelikeKind::File;
+}
+
+/// Inspects a raw socketlike descriptor to determine whether it's actually
+/// a socket.
+#[cfg(any(unix, target_os = "wasi"))]
+pub(crate) fn is_socketlike(raw: RawSocketlike) -> std::io::Result<bool> {
+    Ok(filelike_kind(raw)? == FilelikeKind::Socket)
+}
+
+/// Inspects a raw socketlike descriptor to determine whether it's actually
+/// a socket.
+#[cfg(windows)]
+pub(crate) fn is_socketlike(raw: RawSocketlike) -> std::io::Result<bool> {
+    use std::mem::size_of;
+    use windows_sys::Win32::Networking::WinSock::{getsockopt, SOCKET, SOL_SOCKET, SO_TYPE};
+
+    let mut kind: i32 = 0;
+    let mut kind_len = size_of::<i32>() as i32;
+    // Safety: `raw` is a valid, open socket or handle for the duration of
+    // this call; `getsockopt` on a non-socket handle fails cleanly rather
+    // than reading out-of-bounds memory.
+    let ret = unsafe {
+        getsockopt(
+            raw as SOCKET,
+            SOL_SOCKET,
+            SO_TYPE,
+            &mut kind as *mut i32 as *mut u8,
+            &mut kind_len,
+        )
+    };
+    Ok(ret == 0)
+}
+
+/// A trait to express the ability to attempt to construct an object from a
+/// socketlike object, validating that the underlying descriptor is
+/// actually a socket before committing to the conversion.
+///
+/// Unlike [`FromSocketlike`], which trusts the caller, this is useful when
+/// the descriptor came from an untrusted or out-of-band source and there's
+/// no static guarantee that it's actually a socket.
+#[cfg(any(unix, target_os = "wasi", windows))]
+pub trait TryFromSocketlike: FromSocketlike {
+    /// Constructs a new instance of `Self` from the given socketlike
+    /// object, if it is actually a socket.
+    ///
+    /// On failure, returns the original `owned` object back to the
+    /// caller, so that the descriptor isn't leaked.
+    fn try_from_socketlike(owned: OwnedSocketlike) -> Result<Self, OwnedSocketlike> {
+        match is_socketlike(owned.as_raw_socketlike()) {
+            Ok(true) => Ok(Self::from_socketlike(owned)),
+            _ => Err(owned),
+        }
+    }
+}
+
+#[cfg(any(unix, target_os = "wasi", windows))]
+impl<T: FromSocketlike> TryFromSocketlike for T {}
+
+/// A borrowed pipelike reference.
+///
+/// On Unix this is an anonymous or named pipe file descriptor, exactly
+/// like [`BorrowedFilelike`]. On Windows this is a pipe `HANDLE`; unlike
+/// `BorrowedFilelike`, it specifically promises to refer to a pipe rather
+/// than a regular file, so it doesn't support seeking and may be used
+/// with overlapped I/O.
+#[cfg(any(unix, target_os = "wasi"))]
+pub type BorrowedPipelike<'owned> = BorrowedFd<'owned>;
+
+/// A borrowed pipelike reference.
+///
+/// On Unix this is an anonymous or named pipe file descriptor, exactly
+/// like [`BorrowedFilelike`]. On Windows this is a pipe `HANDLE`; unlike
+/// `BorrowedFilelike`, it specifically promises to refer to a pipe rather
+/// than a regular file, so it doesn't support seeking and may be used
+/// with overlapped I/O.
+#[cfg(windows)]
+pub type BorrowedPipelike<'owned> = BorrowedHandle<'owned>;
+
+/// An owned pipelike object.
+#[cfg(any(unix, target_os = "wasi"))]
+pub type OwnedPipelike = OwnedFd;
+
+/// An owned pipelike object.
+#[cfg(windows)]
+pub type OwnedPipelike = OwnedHandle;
+
+#[cfg(any(unix, target_os = "wasi"))]
+pub(crate) type RawPipelike = RawFd;
+
+#[cfg(windows)]
+pub(crate) type RawPipelike = RawHandle;
+
+/// A trait to borrow a pipelike reference from an underlying object.
+#[cfg(any(unix, target_os = "wasi"))]
+pub trait AsPipelike: AsFd {
+    /// Extracts the pipelike reference.
+    fn as_pipelike(&self) -> BorrowedPipelike<'_>;
+}
+
+#[cfg(any(unix, target_os = "wasi"))]
+impl<T: AsFd> AsPipelike for T {
+    #[inline]
+    fn as_pipelike(&self) -> BorrowedPipelike<'_> {
+        self.as_fd()
+    }
+}
+
+/// A trait to borrow a pipelike reference from an underlying object.
+#[cfg(windows)]
+pub trait AsPipelike: AsHandle {
+    /// Extracts the pipelike reference.
+    fn as_pipelike(&self) -> BorrowedPipelike<'_>;
+}
+
+#[cfg(windows)]
+impl<T: AsHandle> AsPipelike for T {
+    #[inline]
+    fn as_pipelike(&self) -> BorrowedPipelike<'_> {
+        self.as_handle()
+    }
+}
+
+/// A trait to express the ability to consume an object and acquire
+/// ownership of its pipelike object.
+#[cfg(any(unix, target_os = "wasi"))]
+pub trait IntoPipelike: IntoFd {
+    /// Consumes this object, returning the underlying pipelike object.
+    fn into_pipelike(self) -> OwnedPipelike;
+}
+
+#[cfg(any(unix, target_os = "wasi"))]
+impl<T: IntoFd> IntoPipelike for T {
+    #[inline]
+    fn into_pipelike(self) -> OwnedPipelike {
+        self.into_fd()
+    }
+}
+
+/// A trait to express the ability to consume an object and acquire
+/// ownership of its pipelike object.
+#[cfg(windows)]
+pub trait IntoPipelike: IntoHandle {
+    /// Consumes this object, returning the underlying pipelike object.
+    fn into_pipelike(self) -> OwnedPipelike;
+}
+
+#[cfg(windows)]
+impl<T: IntoHandle> IntoPipelike for T {
+    #[inline]
+    fn into_pipelike(self) -> OwnedPipelike {
+        self.into_handle()
+    }
+}
+
+/// A trait to express the ability to construct an object from a pipelike
+/// object.
+#[cfg(any(unix, target_os = "wasi"))]
+pub trait FromPipelike: FromFd {
+    /// Constructs a new instance of `Self` from the given pipelike object.
+    fn from_pipelike(owned: OwnedPipelike) -> Self;
+
+    /// Constructs a new instance of `Self` from the given pipelike object
+    /// converted from `into_owned`.
+    fn from_into_pipelike<Owned: IntoPipelike>(owned: Owned) -> Self;
+}
+
+#[cfg(any(unix, target_os = "wasi"))]
+impl<T: FromFd> FromPipelike for T {
+    #[inline]
+    fn from_pipelike(owned: OwnedPipelike) -> Self {
+        Self::from_fd(owned)
+    }
+
+    #[inline]
+    fn from_into_pipelike<Owned: IntoPipelike>(owned: Owned) -> Self {
+        Self::from_pipelike(owned.into_pipelike())
+    }
+}
+
+/// A trait to express the ability to construct an object from a pipelike
+/// object.
+#[cfg(windows)]
+pub trait FromPipelike: FromHandle {
+    /// Constructs a new instance of `Self` from the given pipelike object.
+    fn from_pipelike(owned: OwnedPipelike) -> Self;
+
+    /// Constructs a new instance of `Self` from the given pipelike object
+    /// converted from `into_owned`.
+    fn from_into_pipelike<Owned: IntoPipelike>(owned: Owned) -> Self;
+}
+
+#[cfg(windows)]
+impl<T: FromHandle> FromPipelike for T {
+    #[inline]
+    fn from_pipelike(owned: OwnedPipelike) -> Self {
+        Self::from_handle(owned)
+    }
+
+    #[inline]
+    fn from_into_pipelike<Owned: IntoPipelike>(owned: Owned) -> Self {
+        Self::from_pipelike(owned.into_pipelike())
+    }
+}
+
+/// A trait to express the ability to attempt to construct an object from a
+/// pipelike object, validating on Windows that the underlying handle's
+/// `GetFileType` is actually `FILE_TYPE_PIPE` before committing to the
+/// conversion. On Unix this falls back to the same `fstat`-based check
+/// used by [`TryFromFilelike`].
+///
+/// This is useful when wrapping an anonymous or named pipe received from
+/// an untrusted or out-of-band source, such as inherited stdio, where
+/// there's no static guarantee that the descriptor is actually a pipe and
+/// not a regular file or console handle.
+#[cfg(any(unix, target_os = "wasi", windows))]
+pub trait TryFromPipelike: FromPipelike {
+    /// Constructs a new instance of `Self` from the given pipelike object,
+    /// if it is actually a pipe.
+    ///
+    /// On failure, returns the original `owned` object back to the
+    /// caller, so that the descriptor isn't leaked.
+    fn try_from_pipelike(owned: OwnedPipelike) -> Result<Self, OwnedPipelike> {
+        match filelike_kind(owned.as_raw_pipelike()) {
+            Ok(FilelikeKind::Pipe) => Ok(Self::from_pipelike(owned)),
+            _ => Err(owned),
+        }
+    }
+}
+
+#[cfg(any(unix, target_os = "wasi", windows))]
+impl<T: FromPipelike> TryFromPipelike for T {}